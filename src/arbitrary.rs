@@ -0,0 +1,90 @@
+//! `proptest::arbitrary::Arbitrary` support, gated behind the `proptest`
+//! feature.
+//!
+//! Strategies are built recursively from a base `T` strategy through `new2`,
+//! matching the structure already encoded informally in `transform.rs`'s
+//! `TestRand` impls, so downstream crates (and this crate's own tests) can
+//! property-test hypercomplex values with shrinking for free.
+#![cfg(feature = "proptest")]
+
+use num_traits::Float;
+
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+
+use crate::traits::Algebra;
+use crate::transform::Moebius;
+use crate::{Complex, Quaternion, Octonion};
+
+
+impl<T> Arbitrary for Complex<T>
+where
+    T: Float + Arbitrary + 'static,
+    T::Parameters: Clone,
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        (any_with::<T>(args.clone()), any_with::<T>(args))
+            .prop_map(|(re, im)| Self::new2(re, im))
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for Quaternion<T>
+where
+    T: Float + Arbitrary + 'static,
+    T::Parameters: Clone,
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        (
+            any_with::<Complex<T>>(args.clone()),
+            any_with::<Complex<T>>(args),
+        )
+            .prop_map(|(re, im)| Self::new2(re, im))
+            .boxed()
+    }
+}
+
+impl<T> Arbitrary for Octonion<T>
+where
+    T: Float + Arbitrary + 'static,
+    T::Parameters: Clone,
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        (
+            any_with::<Quaternion<T>>(args.clone()),
+            any_with::<Quaternion<T>>(args),
+        )
+            .prop_map(|(re, im)| Self::new2(re, im))
+            .boxed()
+    }
+}
+
+impl<T, A> Arbitrary for Moebius<T, A>
+where
+    T: Float + 'static,
+    A: Algebra<T> + Arbitrary + 'static,
+    A::Parameters: Clone,
+{
+    type Parameters = A::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        (
+            any_with::<A>(args.clone()),
+            any_with::<A>(args.clone()),
+            any_with::<A>(args.clone()),
+            any_with::<A>(args),
+        )
+            .prop_map(|(a, b, c, d)| Self::new(a, b, c, d))
+            .boxed()
+    }
+}
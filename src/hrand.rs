@@ -0,0 +1,193 @@
+//! Integration with the `rand` crate, gated behind the `rand` feature.
+//!
+//! Mirrors the `Distribution<Complex<T>> for Standard` pattern from
+//! num-complex's `crand.rs`, threaded through the Cayley-Dickson tower via
+//! the `new2` constructor: `Quaternion` samples two `Complex` halves,
+//! `Octonion` samples two `Quaternion` halves, and so on. Each layer below
+//! is still its own hand-written impl, so a new algebra layer added
+//! elsewhere in the crate needs its own one-line `Distribution` impl
+//! following the same pattern, rather than getting one automatically.
+//!
+//! A single blanket `impl<T: Float, A: Algebra<T>> Distribution<A> for
+//! Standard` isn't possible here: both `Distribution` and `Standard` are
+//! foreign (from `rand`), and orphan/coherence rules only allow a foreign
+//! trait to be implemented for a foreign type when a *local* type appears
+//! in a covered position — a fully generic `A: Algebra<T>` doesn't count,
+//! since it could in principle be instantiated with another foreign type.
+//! Per-type impls (or a sealed, crate-local enumeration of algebras) are
+//! the only way around that, which is what this module does.
+#![cfg(feature = "rand")]
+
+use num_traits::Float;
+
+use rand::Rng;
+use rand::distributions::{Distribution, Standard, StandardNormal};
+
+use std::f64::consts::PI;
+
+use crate::traits::{Algebra, Norm};
+use crate::transform::Moebius;
+use crate::{Complex, Quaternion, Octonion};
+
+
+impl<T> Distribution<Complex<T>> for Standard
+where
+    T: Float,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Complex<T> {
+        Complex::new2(self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T> Distribution<Quaternion<T>> for Standard
+where
+    T: Float,
+    Standard: Distribution<Complex<T>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quaternion<T> {
+        Quaternion::new2(self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T> Distribution<Octonion<T>> for Standard
+where
+    T: Float,
+    Standard: Distribution<Quaternion<T>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Octonion<T> {
+        Octonion::new2(self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T, A> Distribution<Moebius<T, A>> for Standard
+where
+    T: Float,
+    A: Algebra<T>,
+    Standard: Distribution<A>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Moebius<T, A> {
+        Moebius::new(self.sample(rng), self.sample(rng), self.sample(rng), self.sample(rng))
+    }
+}
+
+/// Draws each real component of an algebra value from a single, user-chosen
+/// component distribution `D`, folding the `2^k` i.i.d. samples through
+/// `new2` down to the base field.
+///
+/// Ports num-complex's `ComplexDistribution<Re, Im>` up the whole
+/// Cayley-Dickson tower: pass in `Normal`, `Uniform`, `StandardNormal`, or
+/// anything else implementing `Distribution<T>`, and get Gaussian octonions
+/// or uniformly-bounded quaternions without hand-writing the recursion.
+#[derive(Clone, Copy, Debug)]
+pub struct AlgebraDistribution<D> {
+    component: D,
+}
+
+impl<D> AlgebraDistribution<D> {
+    pub fn new(component: D) -> Self {
+        Self { component }
+    }
+}
+
+impl<T, D> Distribution<Complex<T>> for AlgebraDistribution<D>
+where
+    T: Float,
+    D: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Complex<T> {
+        Complex::new2(self.component.sample(rng), self.component.sample(rng))
+    }
+}
+
+impl<T, D> Distribution<Quaternion<T>> for AlgebraDistribution<D>
+where
+    T: Float,
+    D: Distribution<T>,
+    AlgebraDistribution<D>: Distribution<Complex<T>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quaternion<T> {
+        Quaternion::new2(self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T, D> Distribution<Octonion<T>> for AlgebraDistribution<D>
+where
+    T: Float,
+    D: Distribution<T>,
+    AlgebraDistribution<D>: Distribution<Quaternion<T>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Octonion<T> {
+        Octonion::new2(self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T, A, D> Distribution<Moebius<T, A>> for AlgebraDistribution<D>
+where
+    T: Float,
+    A: Algebra<T>,
+    AlgebraDistribution<D>: Distribution<A>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Moebius<T, A> {
+        Moebius::new(self.sample(rng), self.sample(rng), self.sample(rng), self.sample(rng))
+    }
+}
+
+/// Quaternions drawn uniformly from the unit 3-sphere, i.e. Haar-uniform
+/// rotations in SO(3).
+///
+/// Analogous to rand's `UnitSphere`/`UnitCircle`, but specialized to
+/// `Quaternion<T>` via Shoemake's method: three uniform draws `u1, u2, u3` in
+/// `[0, 1)` are turned into the four components
+/// `sqrt(1-u1)*sin(2*pi*u2)`, `sqrt(1-u1)*cos(2*pi*u2)`,
+/// `sqrt(u1)*sin(2*pi*u3)`, `sqrt(u1)*cos(2*pi*u3)`, which always has norm 1.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitQuaternion;
+
+impl<T> Distribution<Quaternion<T>> for UnitQuaternion
+where
+    T: Float,
+    Standard: Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quaternion<T> {
+        let two_pi = T::from(2.0 * PI).unwrap();
+        let u1: T = rng.gen();
+        let u2: T = rng.gen();
+        let u3: T = rng.gen();
+
+        let r1 = (T::one() - u1).sqrt();
+        let r2 = u1.sqrt();
+        let t1 = two_pi * u2;
+        let t2 = two_pi * u3;
+
+        Quaternion::new2(
+            Complex::new2(r1 * t1.sin(), r1 * t1.cos()),
+            Complex::new2(r2 * t2.sin(), r2 * t2.cos()),
+        )
+    }
+}
+
+/// Uniform distribution over the unit sphere of any algebra, by sampling
+/// `2^k` standard-normal components and normalizing by the Euclidean norm.
+///
+/// The all-zero draw has measure zero and is simply rejected by resampling.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSphere;
+
+impl<T, A> Distribution<A> for UnitSphere
+where
+    T: Float,
+    A: Algebra<T> + Norm<Output = T>,
+    AlgebraDistribution<StandardNormal>: Distribution<A>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> A {
+        let dist = AlgebraDistribution::new(StandardNormal);
+        loop {
+            let v: A = dist.sample(rng);
+            let norm = v.norm();
+            if norm > T::zero() {
+                return v * (T::one() / norm);
+            }
+        }
+    }
+}
@@ -3,12 +3,30 @@ use num_traits::{Float};
 use std::marker::PhantomData;
 
 use crate::traits::{Algebra};
+use crate::Complex;
 
 
 pub trait Transform<T: Float, A: Algebra<T>> {
     fn apply(&self, x: A) -> A;
 }
 
+/// Marker for algebras whose multiplication commutes.
+///
+/// `Moebius::determinant`/`inverse`/`normalize` use the classical 2x2
+/// adjugate formula (`a*d - b*c`, etc.), which only computes the correct
+/// matrix inverse when `a`, `b`, `c`, `d` commute. `Quaternion` and
+/// `Octonion` don't satisfy that — the adjugate of a matrix over a
+/// noncommutative ring needs a Schur-complement construction instead — so
+/// those methods are restricted to `A: Commutative`.
+///
+/// Quaternionic Moebius inverse (quaternions are a division ring, so a
+/// correct GL(2,H)-style inverse does exist in principle) is intentionally
+/// unsupported for now: nobody has implemented the Schur-complement
+/// construction yet. This is a deliberate scope cut, not an oversight.
+pub trait Commutative {}
+
+impl<T: Float> Commutative for Complex<T> {}
+
 pub trait Chain<T: Float, A: Algebra<T>> {
     fn chain(&self, other: &Self) -> Self;
 }
@@ -30,6 +48,42 @@ impl<T: Float, A: Algebra<T>> Moebius<T, A> {
     pub fn new(a: A, b: A, c: A, d: A) -> Self {
         Self { a, b, c, d, pd: PhantomData }
     }
+
+    /// The identity transform `(1, 0, 0, 1)`, i.e. `apply` is the identity map.
+    pub fn identity() -> Self {
+        Self::new(A::one(), A::zero(), A::zero(), A::one())
+    }
+}
+
+impl<T: Float, A: Algebra<T> + Commutative> Moebius<T, A> {
+    /// The determinant `a*d - b*c` of the underlying 2x2 matrix.
+    pub fn determinant(&self) -> A {
+        self.a*self.d - self.b*self.c
+    }
+
+    /// The inverse transform `(d, -b, -c, a) / determinant()`, such that
+    /// `self.chain(&self.inverse())` is the identity transform.
+    pub fn inverse(&self) -> Self {
+        let det = self.determinant();
+        Self::new(self.d/det, -self.b/det, -self.c/det, self.a/det)
+    }
+}
+
+impl<T: Float> Moebius<T, Complex<T>> {
+    /// Scales all four coefficients so that `determinant()` becomes one.
+    ///
+    /// `sqrt` is only available on `Complex` itself (it isn't part of
+    /// `Algebra` or `Commutative`), so this isn't generic over `A` the way
+    /// `determinant`/`inverse` are.
+    ///
+    /// Panics if the determinant is zero, since there is no scale that
+    /// makes a singular matrix's determinant one.
+    pub fn normalize(&self) -> Self {
+        let det = self.determinant();
+        assert!(det != Complex::new2(T::zero(), T::zero()), "Moebius::normalize: singular determinant");
+        let scale = det.sqrt();
+        Self::new(self.a/scale, self.b/scale, self.c/scale, self.d/scale)
+    }
 }
 
 impl<T: Float, A: Algebra<T>> Chain<T, A> for Moebius<T, A> {
@@ -162,6 +216,30 @@ mod test {
         }
     }
 
+    #[test]
+    fn moebius2_inverse() {
+        let mut rng = TestRng::new();
+        for _ in 0..TRANSFORM_ATTEMPTS {
+            let a = Moebius::<f64, Complex<f64>>::random(&mut rng);
+            let ia = a.chain(&a.inverse());
+            for _ in 0..POINT_ATTEMPTS {
+                let x = Complex::random(&mut rng);
+                let y = ia.apply(x);
+                assert_approx_eq!(x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn moebius2_normalize() {
+        let mut rng = TestRng::new();
+        for _ in 0..TRANSFORM_ATTEMPTS {
+            let a = Moebius::<f64, Complex<f64>>::random(&mut rng);
+            let n = a.normalize();
+            assert_approx_eq!(n.determinant(), Complex::new2(1., 0.));
+        }
+    }
+
     /// Moebuis transform over octonions isn't chainable and therefore should fail
     #[test]
     #[should_panic]